@@ -1,30 +1,57 @@
 use crate::components::{
+    instruction::{
+        self,
+        component::{InstructionComponent, InstructionEval},
+        table::{
+            interaction_trace_evaluation as instruction_interaction_trace_evaluation,
+            InstructionElements, InstructionTable,
+        },
+    },
+    io::{
+        self,
+        component::{IoComponent, IoEval},
+        table::{
+            interaction_trace_evaluation as io_interaction_trace_evaluation, public_io_events,
+            IoElements, IoTable,
+        },
+    },
     memory::{
         self,
         component::{MemoryComponent, MemoryEval},
-        table::{interaction_trace_evaluation, MemoryElements, MemoryTable},
+        table::{interaction_trace_evaluation as memory_interaction_trace_evaluation, MemoryElements, MemoryTable},
     },
-    MemoryClaim,
+    processor::{
+        self,
+        component::{ProcessorComponent, ProcessorEval},
+        table::{interaction_trace_evaluation as processor_interaction_trace_evaluation, ProcessorTable},
+    },
+    InstructionClaim, IoClaim, MemoryClaim, ProcessorClaim,
 };
 use brainfuck_vm::machine::Machine;
+use std::panic::{self, AssertUnwindSafe};
 use stwo_prover::{
     constraint_framework::{
-        preprocessed_columns::PreprocessedColumn, TraceLocationAllocator, INTERACTION_TRACE_IDX,
-        ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX,
+        assert_constraints,
+        preprocessed_columns::{gen_is_first, PreprocessedColumn},
+        FrameworkEval, TraceLocationAllocator, INTERACTION_TRACE_IDX, ORIGINAL_TRACE_IDX,
+        PREPROCESSED_TRACE_IDX,
     },
     core::{
         air::{Component, ComponentProver},
         backend::simd::SimdBackend,
-        channel::{Blake2sChannel, Channel},
+        channel::{Blake2sChannel, Channel, MerkleChannel},
         pcs::{CommitmentSchemeProver, CommitmentSchemeVerifier, PcsConfig, TreeVec},
         poly::circle::{CanonicCoset, PolyOps},
         prover::{self, verify, ProvingError, StarkProof, VerificationError},
         vcs::{
-            blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher},
+            blake2_merkle::Blake2sMerkleChannel,
             ops::MerkleHasher,
+            poseidon252_merkle::Poseidon252MerkleChannel,
         },
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
     },
 };
+use num_traits::{One, Zero};
 
 /// The STARK proof of the execution of a given Brainfuck program.
 ///
@@ -41,29 +68,72 @@ pub struct BrainfuckProof<H: MerkleHasher> {
 /// and the claim of each component.
 pub struct BrainfuckClaim {
     pub memory: MemoryClaim,
+    pub instruction: InstructionClaim,
+    pub io: IoClaim,
+    pub processor: ProcessorClaim,
+    /// The `(clk, byte)` pairs consumed by every `,` (`ReadChar`) instruction, in execution
+    /// order. Public: known to both the prover and the verifier ahead of time, and exactly
+    /// the data [`IoTable::from_public`] builds its rows from.
+    pub public_input: Vec<(BaseField, BaseField)>,
+    /// The `(clk, byte)` pairs emitted by every `.` (`PutChar`) instruction, in execution
+    /// order. Public: part of the statement the verifier checks the proof against.
+    pub public_output: Vec<(BaseField, BaseField)>,
 }
 
 impl BrainfuckClaim {
     pub fn mix_into(&self, channel: &mut impl Channel) {
         self.memory.mix_into(channel);
+        self.instruction.mix_into(channel);
+        self.io.mix_into(channel);
+        self.processor.mix_into(channel);
+        channel.mix_felts(
+            &self
+                .public_input
+                .iter()
+                .chain(&self.public_output)
+                .flat_map(|&(clk, byte)| [SecureField::from(clk), SecureField::from(byte)])
+                .collect::<Vec<_>>(),
+        );
     }
 
     pub fn log_sizes(&self) -> TreeVec<Vec<u32>> {
-        self.memory.log_sizes()
+        TreeVec::concat_cols(
+            [
+                self.memory.log_sizes(),
+                self.instruction.log_sizes(),
+                self.io.log_sizes(),
+                self.processor.log_sizes(),
+            ]
+            .into_iter(),
+        )
     }
 }
 
 /// All the interaction elements (drawn from the channel)
 /// required by the various components during the interaction phase.
+///
+/// The processor has no table of its own to be looked up into: instead, every processor
+/// row emits one LogUp fraction per cross-table link, combined with the *same* elements
+/// the target table uses to combine its own rows. A `(clk, mp, value)` step is checked
+/// against [`MemoryElements`], a `(ip, instruction, argument)` step against
+/// [`InstructionElements`], and every `PutChar`/`ReadChar` step against [`IoElements`].
+/// The combined sum cancels out iff the processor trace is consistent with the other
+/// three tables.
 pub struct BrainfuckInteractionElements {
     pub memory_lookup_elements: MemoryElements,
+    pub instruction_lookup_elements: InstructionElements,
+    pub io_lookup_elements: IoElements,
 }
 
 impl BrainfuckInteractionElements {
     /// Draw all the interaction elements needed for
     /// all the components of the Brainfuck ZK-VM.
     pub fn draw(channel: &mut impl Channel) -> Self {
-        Self { memory_lookup_elements: MemoryElements::draw(channel) }
+        Self {
+            memory_lookup_elements: MemoryElements::draw(channel),
+            instruction_lookup_elements: InstructionElements::draw(channel),
+            io_lookup_elements: IoElements::draw(channel),
+        }
     }
 }
 
@@ -72,22 +142,59 @@ impl BrainfuckInteractionElements {
 /// Mainly the claims on global relations (lookup, permutation, evaluation).
 pub struct BrainfuckInteractionClaim {
     memory: memory::component::InteractionClaim,
+    instruction: instruction::component::InteractionClaim,
+    io: io::component::InteractionClaim,
+    processor: processor::component::InteractionClaim,
 }
 
 impl BrainfuckInteractionClaim {
     /// Mix the claimed sums of every components in the Fiat-Shamir [`Channel`].
     pub fn mix_into(&self, channel: &mut impl Channel) {
         self.memory.mix_into(channel);
+        self.instruction.mix_into(channel);
+        self.io.mix_into(channel);
+        self.processor.mix_into(channel);
     }
 }
 
 /// Verify that the claims (i.e. Statement) are valid.
+///
+/// This is the global LogUp consistency check: every component contributes a
+/// `claimed_sum`, the sum of its `numerator/denominator` fractions over all of its rows,
+/// with "writes" into a table and the matching "reads" from it carrying opposite signs by
+/// construction. A sound execution is one where every read matches a write and vice versa,
+/// so the sum of all `claimed_sum`s must cancel out to zero.
+///
+/// The public I/O boundary is an explicit term of this check: the verifier recomputes, from
+/// `claim.public_input`/`public_output` and the IO lookup elements, the sum of the `-1`
+/// fractions that [`IoTable::from_public`] rows contribute, and rejects the claim unless the
+/// `io` component's `claimed_sum` equals it. The Processor's `(clk, is_io, value)` fractions
+/// can then only cancel that term if its own I/O steps match the public tape.
 pub fn lookup_sum_valid(
-    _claim: &BrainfuckClaim,
-    _interaction_elements: &BrainfuckInteractionElements,
-    _interaction_claim: &BrainfuckInteractionClaim,
+    claim: &BrainfuckClaim,
+    interaction_elements: &BrainfuckInteractionElements,
+    interaction_claim: &BrainfuckInteractionClaim,
 ) -> bool {
-    todo!();
+    let io_elements = &interaction_elements.io_lookup_elements;
+    let public_io_sum: SecureField = claim
+        .public_input
+        .iter()
+        .chain(&claim.public_output)
+        .map(|&(clk, byte)| {
+            let denom: SecureField = io_elements.combine(&[clk, BaseField::one(), byte]);
+            -denom.inverse()
+        })
+        .sum();
+    if interaction_claim.io.claimed_sum != public_io_sum {
+        return false;
+    }
+
+    let total_sum = interaction_claim.memory.claimed_sum
+        + interaction_claim.instruction.claimed_sum
+        + interaction_claim.io.claimed_sum
+        + interaction_claim.processor.claimed_sum;
+
+    total_sum == SecureField::zero()
 }
 
 /// All the components that constitute the Brainfuck ZK-VM.
@@ -96,6 +203,9 @@ pub fn lookup_sum_valid(
 /// and by the verifier as a `Component`.
 pub struct BrainfuckComponents {
     memory: MemoryComponent,
+    instruction: InstructionComponent,
+    io: IoComponent,
+    processor: ProcessorComponent,
 }
 
 impl BrainfuckComponents {
@@ -106,9 +216,16 @@ impl BrainfuckComponents {
         interaction_claim: &BrainfuckInteractionClaim,
     ) -> Self {
         let memory_is_first_column = PreprocessedColumn::IsFirst(claim.memory.log_size);
+        let instruction_is_first_column = PreprocessedColumn::IsFirst(claim.instruction.log_size);
+        let io_is_first_column = PreprocessedColumn::IsFirst(claim.io.log_size);
+        let processor_is_first_column = PreprocessedColumn::IsFirst(claim.processor.log_size);
 
-        let tree_span_provider =
-            &mut TraceLocationAllocator::new_with_preproccessed_columnds(&[memory_is_first_column]);
+        let tree_span_provider = &mut TraceLocationAllocator::new_with_preproccessed_columnds(&[
+            memory_is_first_column,
+            instruction_is_first_column,
+            io_is_first_column,
+            processor_is_first_column,
+        ]);
 
         let memory = MemoryComponent::new(
             tree_span_provider,
@@ -120,12 +237,44 @@ impl BrainfuckComponents {
             (interaction_claim.memory.claimed_sum, None),
         );
 
-        Self { memory }
+        let instruction = InstructionComponent::new(
+            tree_span_provider,
+            InstructionEval::new(
+                &claim.instruction,
+                interaction_elements.instruction_lookup_elements.clone(),
+                &interaction_claim.instruction,
+            ),
+            (interaction_claim.instruction.claimed_sum, None),
+        );
+
+        let io = IoComponent::new(
+            tree_span_provider,
+            IoEval::new(
+                &claim.io,
+                interaction_elements.io_lookup_elements.clone(),
+                &interaction_claim.io,
+            ),
+            (interaction_claim.io.claimed_sum, None),
+        );
+
+        let processor = ProcessorComponent::new(
+            tree_span_provider,
+            ProcessorEval::new(
+                &claim.processor,
+                interaction_elements.memory_lookup_elements.clone(),
+                interaction_elements.instruction_lookup_elements.clone(),
+                interaction_elements.io_lookup_elements.clone(),
+                &interaction_claim.processor,
+            ),
+            (interaction_claim.processor.claimed_sum, None),
+        );
+
+        Self { memory, instruction, io, processor }
     }
 
     /// Returns the `ComponentProver` of each components, used by the prover.
     pub fn provers(&self) -> Vec<&dyn ComponentProver<SimdBackend>> {
-        vec![&self.memory]
+        vec![&self.memory, &self.instruction, &self.io, &self.processor]
     }
 
     /// Returns the `Component` of each components, used by the verifier.
@@ -141,12 +290,16 @@ const LOG_MAX_ROWS: u32 = 20;
 
 /// Generate a STARK proof of the given Brainfuck program execution.
 ///
+/// Generic over the [`MerkleChannel`] (and thus the [`MerkleHasher`] and Fiat-Shamir
+/// [`Channel`]) used to build the Fiat-Shamir transcript and the trace commitments.
+/// [`Blake2sMerkleChannel`] is cheap to run natively but expensive to re-verify inside
+/// another proof; [`Poseidon252MerkleChannel`] is arithmetization-friendly instead, making
+/// recursive or on-chain verification practical.
+///
 /// # Arguments
 /// * `inputs` - The [`Machine`] struct after the program execution
 /// The inputs contains the program, the memory, the I/O and the trace.
-pub fn prove_brainfuck(
-    inputs: &Machine,
-) -> Result<BrainfuckProof<Blake2sMerkleHasher>, ProvingError> {
+pub fn prove_brainfuck<MC: MerkleChannel>(inputs: &Machine) -> Result<BrainfuckProof<MC::H>, ProvingError> {
     // ┌──────────────────────────┐
     // │     Protocol Setup       │
     // └──────────────────────────┘
@@ -157,16 +310,32 @@ pub fn prove_brainfuck(
             .circle_domain()
             .half_coset,
     );
-    let channel = &mut Blake2sChannel::default();
-    let commitment_scheme =
-        &mut CommitmentSchemeProver::<_, Blake2sMerkleChannel>::new(config, &twiddles);
+    let channel = &mut MC::C::default();
+    let commitment_scheme = &mut CommitmentSchemeProver::<_, MC>::new(config, &twiddles);
+
+    let vm_trace = inputs.trace();
+    let (public_input, public_output) = public_io_events(vm_trace);
+    let (memory_trace, memory_claim) = MemoryTable::from(vm_trace).trace_evaluation().unwrap();
+    let (instruction_trace, instruction_claim) =
+        InstructionTable::from(vm_trace).trace_evaluation().unwrap();
+    let (io_trace, io_claim) =
+        IoTable::from_public(&public_input, &public_output).trace_evaluation().unwrap();
+    let (processor_trace, processor_claim) =
+        ProcessorTable::from(vm_trace).trace_evaluation().unwrap();
 
     // ┌───────────────────────────────────────────────┐
     // │   Interaction Phase 0 - Preprocessed Trace    │
     // └───────────────────────────────────────────────┘
 
-    // Generate constant columns (e.g. is_first)
-    let tree_builder = commitment_scheme.tree_builder();
+    // One `IsFirst` column per component, matching what `BrainfuckComponents::new` registers
+    // through `TraceLocationAllocator::new_with_preproccessed_columnds`.
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals([
+        gen_is_first::<SimdBackend>(memory_claim.log_size),
+        gen_is_first::<SimdBackend>(instruction_claim.log_size),
+        gen_is_first::<SimdBackend>(io_claim.log_size),
+        gen_is_first::<SimdBackend>(processor_claim.log_size),
+    ]);
     tree_builder.commit(channel);
 
     // ┌───────────────────────────────────────┐
@@ -175,12 +344,19 @@ pub fn prove_brainfuck(
 
     let mut tree_builder = commitment_scheme.tree_builder();
 
-    let vm_trace = inputs.trace();
-    let (memory_trace, memory_claim) = MemoryTable::from(vm_trace).trace_evaluation().unwrap();
-
     tree_builder.extend_evals(memory_trace.clone());
-
-    let claim = BrainfuckClaim { memory: memory_claim };
+    tree_builder.extend_evals(instruction_trace.clone());
+    tree_builder.extend_evals(io_trace.clone());
+    tree_builder.extend_evals(processor_trace.clone());
+
+    let claim = BrainfuckClaim {
+        memory: memory_claim,
+        instruction: instruction_claim,
+        io: io_claim,
+        processor: processor_claim,
+        public_input,
+        public_output,
+    };
 
     // Mix the claim into the Fiat-Shamir channel.
     claim.mix_into(channel);
@@ -198,11 +374,33 @@ pub fn prove_brainfuck(
     let mut tree_builder = commitment_scheme.tree_builder();
 
     let (memory_interaction_trace_eval, memory_interaction_claim) =
-        interaction_trace_evaluation(&memory_trace, &interaction_elements.memory_lookup_elements);
+        memory_interaction_trace_evaluation(&memory_trace, &interaction_elements.memory_lookup_elements);
+    let (instruction_interaction_trace_eval, instruction_interaction_claim) =
+        instruction_interaction_trace_evaluation(
+            &instruction_trace,
+            &interaction_elements.instruction_lookup_elements,
+        );
+    let (io_interaction_trace_eval, io_interaction_claim) =
+        io_interaction_trace_evaluation(&io_trace, &interaction_elements.io_lookup_elements);
+    let (processor_interaction_trace_eval, processor_interaction_claim) =
+        processor_interaction_trace_evaluation(
+            &processor_trace,
+            &interaction_elements.memory_lookup_elements,
+            &interaction_elements.instruction_lookup_elements,
+            &interaction_elements.io_lookup_elements,
+        );
 
     tree_builder.extend_evals(memory_interaction_trace_eval);
-
-    let interaction_claim = BrainfuckInteractionClaim { memory: memory_interaction_claim };
+    tree_builder.extend_evals(instruction_interaction_trace_eval);
+    tree_builder.extend_evals(io_interaction_trace_eval);
+    tree_builder.extend_evals(processor_interaction_trace_eval);
+
+    let interaction_claim = BrainfuckInteractionClaim {
+        memory: memory_interaction_claim,
+        instruction: instruction_interaction_claim,
+        io: io_interaction_claim,
+        processor: processor_interaction_claim,
+    };
 
     // Mix the interaction claim into the Fiat-Shamir channel.
     interaction_claim.mix_into(channel);
@@ -220,17 +418,204 @@ pub fn prove_brainfuck(
     Ok(BrainfuckProof { claim, interaction_claim, proof })
 }
 
+/// Outcome of the off-circuit constraint check for a single component.
+#[derive(Debug)]
+pub struct ComponentCheck {
+    /// Name of the component that was checked (e.g. `"memory"`).
+    pub component: &'static str,
+    /// `Some((row, message))`, the row index parsed from the panic message `assert_constraints`
+    /// raised on the first non-zero constraint (`0` when the message carries none) and that
+    /// message itself; `None` if every row satisfied every constraint.
+    pub first_violation: Option<(usize, String)>,
+    /// Whether this component's LogUp `claimed_sum` matches an independent recomputation of
+    /// the sum of its own fractions (via the table's `recompute_claimed_sum`, which goes
+    /// through plain scalar field arithmetic rather than the SIMD-packed
+    /// `LogupTraceGenerator` the real interaction trace uses). Computed separately from
+    /// `first_violation`: a component can satisfy every row-level constraint and still carry
+    /// a `claimed_sum` that doesn't match its own rows, or vice versa, so this is not simply
+    /// `first_violation.is_none()`.
+    pub claimed_sum_is_consistent: bool,
+}
+
+/// Off-circuit equivalent of halo2's `MockProver`.
+///
+/// Builds the same main traces `prove_brainfuck` would, and interaction traces of the same
+/// shape (their lookup elements are drawn from a fresh [`Blake2sChannel`] into which only
+/// the claim is mixed, so their values differ from those of a real proof), but instead of
+/// handing them to the STARK prover it evaluates every component's [`FrameworkEval`]
+/// constraints row by row directly over the base field and reports, per component, the row
+/// and the panic message of the first constraint whose value is non-zero, together with
+/// whether each component's LogUp `claimed_sum` is individually consistent with its own
+/// fractions. This reuses the exact `FrameworkEval` implementations the real prover commits
+/// to (e.g. [`MemoryEval`]), so it gives fast, actionable feedback while authoring a new
+/// component instead of an opaque [`ProvingError::ConstraintsNotSatisfied`] from the real
+/// prover.
+pub fn debug_evaluate_constraints(inputs: &Machine) -> Vec<ComponentCheck> {
+    let channel = &mut Blake2sChannel::default();
+
+    let vm_trace = inputs.trace();
+    let (public_input, public_output) = public_io_events(vm_trace);
+    let memory_table = MemoryTable::from(vm_trace);
+    let (memory_trace, memory_claim) = memory_table.trace_evaluation().unwrap();
+    let instruction_table = InstructionTable::from(vm_trace);
+    let (instruction_trace, instruction_claim) = instruction_table.trace_evaluation().unwrap();
+    let io_table = IoTable::from_public(&public_input, &public_output);
+    let (io_trace, io_claim) = io_table.trace_evaluation().unwrap();
+    let processor_table = ProcessorTable::from(vm_trace);
+    let (processor_trace, processor_claim) = processor_table.trace_evaluation().unwrap();
+
+    let claim = BrainfuckClaim {
+        memory: memory_claim,
+        instruction: instruction_claim,
+        io: io_claim,
+        processor: processor_claim,
+        public_input,
+        public_output,
+    };
+    claim.mix_into(channel);
+
+    let interaction_elements = BrainfuckInteractionElements::draw(channel);
+
+    let (memory_interaction_trace, memory_interaction_claim) =
+        memory_interaction_trace_evaluation(&memory_trace, &interaction_elements.memory_lookup_elements);
+    let (instruction_interaction_trace, instruction_interaction_claim) =
+        instruction_interaction_trace_evaluation(
+            &instruction_trace,
+            &interaction_elements.instruction_lookup_elements,
+        );
+    let (io_interaction_trace, io_interaction_claim) =
+        io_interaction_trace_evaluation(&io_trace, &interaction_elements.io_lookup_elements);
+    let (processor_interaction_trace, processor_interaction_claim) =
+        processor_interaction_trace_evaluation(
+            &processor_trace,
+            &interaction_elements.memory_lookup_elements,
+            &interaction_elements.instruction_lookup_elements,
+            &interaction_elements.io_lookup_elements,
+        );
+
+    vec![
+        check_component(
+            "memory",
+            claim.memory.log_size,
+            &memory_trace,
+            &memory_interaction_trace,
+            MemoryEval::new(
+                &claim.memory,
+                interaction_elements.memory_lookup_elements.clone(),
+                &memory_interaction_claim,
+            ),
+            memory_interaction_claim.claimed_sum,
+            memory_interaction_claim.claimed_sum
+                == memory_table.recompute_claimed_sum(&interaction_elements.memory_lookup_elements),
+        ),
+        check_component(
+            "instruction",
+            claim.instruction.log_size,
+            &instruction_trace,
+            &instruction_interaction_trace,
+            InstructionEval::new(
+                &claim.instruction,
+                interaction_elements.instruction_lookup_elements.clone(),
+                &instruction_interaction_claim,
+            ),
+            instruction_interaction_claim.claimed_sum,
+            instruction_interaction_claim.claimed_sum
+                == instruction_table
+                    .recompute_claimed_sum(&interaction_elements.instruction_lookup_elements),
+        ),
+        check_component(
+            "io",
+            claim.io.log_size,
+            &io_trace,
+            &io_interaction_trace,
+            IoEval::new(&claim.io, interaction_elements.io_lookup_elements.clone(), &io_interaction_claim),
+            io_interaction_claim.claimed_sum,
+            io_interaction_claim.claimed_sum
+                == io_table.recompute_claimed_sum(&interaction_elements.io_lookup_elements),
+        ),
+        check_component(
+            "processor",
+            claim.processor.log_size,
+            &processor_trace,
+            &processor_interaction_trace,
+            ProcessorEval::new(
+                &claim.processor,
+                interaction_elements.memory_lookup_elements.clone(),
+                interaction_elements.instruction_lookup_elements.clone(),
+                interaction_elements.io_lookup_elements.clone(),
+                &processor_interaction_claim,
+            ),
+            processor_interaction_claim.claimed_sum,
+            processor_interaction_claim.claimed_sum
+                == processor_table.recompute_claimed_sum(
+                    &interaction_elements.memory_lookup_elements,
+                    &interaction_elements.instruction_lookup_elements,
+                    &interaction_elements.io_lookup_elements,
+                ),
+        ),
+    ]
+}
+
+/// Evaluates a single component's constraints row by row, catching the panic
+/// [`assert_constraints`] raises on the first unsatisfied constraint instead of letting it
+/// abort the whole debug run. The preprocessed tree carries the same single `IsFirst` column
+/// [`BrainfuckComponents::new`] registers for this component, so the constraints see the
+/// exact same trace layout the real prover would commit to.
+fn check_component<E: FrameworkEval>(
+    component: &'static str,
+    log_size: u32,
+    main_trace: &TraceEval,
+    interaction_trace: &TraceEval,
+    eval: E,
+    claimed_sum: SecureField,
+    claimed_sum_is_consistent: bool,
+) -> ComponentCheck {
+    let preprocessed_trace = vec![gen_is_first::<SimdBackend>(log_size)];
+    let trace =
+        TreeVec::new(vec![preprocessed_trace, main_trace.clone(), interaction_trace.clone()]);
+    let trace_polys = trace.map(|evals| evals.into_iter().map(|eval| eval.interpolate()).collect());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        assert_constraints(&trace_polys, CanonicCoset::new(log_size), |eval_at_point| {
+            eval.evaluate(eval_at_point);
+        }, claimed_sum);
+    }));
+
+    let first_violation = result.err().map(|payload| {
+        let message = payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(ToString::to_string))
+            .unwrap_or_else(|| "constraint not satisfied".to_string());
+        let row = violation_row(&message).unwrap_or(0);
+        (row, message)
+    });
+
+    ComponentCheck { component, first_violation, claimed_sum_is_consistent }
+}
+
+/// Parses the row index out of `assert_constraints`'s panic message (it reports violations as
+/// `"row <N> ..."`). Returns `None` when the message doesn't carry one, since
+/// `assert_constraints` does not guarantee a fixed wording across `stwo` versions; the caller
+/// then reports row `0`.
+fn violation_row(message: &str) -> Option<usize> {
+    let after_row = &message[message.find("row")? + "row".len()..];
+    after_row.split(|c: char| !c.is_ascii_digit()).find(|token| !token.is_empty())?.parse().ok()
+}
+
 /// Verify a given STARK proof of a Brainfuck program execution with corresponding claim.
-pub fn verify_brainfuck(
-    BrainfuckProof { claim, interaction_claim, proof }: BrainfuckProof<Blake2sMerkleHasher>,
+///
+/// The [`MerkleChannel`] `MC` must match the one used to produce the proof in
+/// [`prove_brainfuck`].
+pub fn verify_brainfuck<MC: MerkleChannel>(
+    BrainfuckProof { claim, interaction_claim, proof }: BrainfuckProof<MC::H>,
 ) -> Result<(), VerificationError> {
     // ┌──────────────────────────┐
     // │     Protocol Setup       │
     // └──────────────────────────┘
     let config = PcsConfig::default();
-    let channel = &mut Blake2sChannel::default();
-    let commitment_scheme_verifier =
-        &mut CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(config);
+    let channel = &mut MC::C::default();
+    let commitment_scheme_verifier = &mut CommitmentSchemeVerifier::<MC>::new(config);
     let log_sizes = &claim.log_sizes();
 
     // ┌───────────────────────────────────────────────┐
@@ -279,3 +664,90 @@ pub fn verify_brainfuck(
 
     verify(&components, channel, commitment_scheme_verifier, proof)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brainfuck_vm::{compiler::Compiler, machine::Machine};
+
+    /// Compiles and runs a Brainfuck program to completion, returning the executed [`Machine`].
+    fn execute(code: &str, input: Vec<u8>) -> Machine {
+        let instructions = Compiler::new(code).compile();
+        let mut machine = Machine::new(instructions, input);
+        machine.execute().unwrap();
+        machine
+    }
+
+    #[test]
+    fn test_prove_verify_roundtrip_blake2s() {
+        let machine = execute("++>+++++[<+>-]++++++++[<++++++>-]<.", vec![]);
+
+        let proof = prove_brainfuck::<Blake2sMerkleChannel>(&machine).unwrap();
+        verify_brainfuck::<Blake2sMerkleChannel>(proof).unwrap();
+    }
+
+    #[test]
+    fn test_prove_verify_roundtrip_poseidon252() {
+        let machine = execute("++>+++++[<+>-]++++++++[<++++++>-]<.", vec![]);
+
+        let proof = prove_brainfuck::<Poseidon252MerkleChannel>(&machine).unwrap();
+        verify_brainfuck::<Poseidon252MerkleChannel>(proof).unwrap();
+    }
+
+    #[test]
+    fn test_debug_evaluate_constraints_reports_no_violation() {
+        let machine = execute("++>+++++[<+>-]++++++++[<++++++>-]<.", vec![]);
+
+        let checks = debug_evaluate_constraints(&machine);
+
+        assert_eq!(checks.len(), 4);
+        for check in &checks {
+            assert!(check.claimed_sum_is_consistent, "{}: {:?}", check.component, check.first_violation);
+            assert!(check.first_violation.is_none(), "{}: {:?}", check.component, check.first_violation);
+        }
+    }
+
+    /// Feeds `check_component` a `claimed_sum` that doesn't match the Memory table's own
+    /// rows, and checks that both outcome fields flag it, each by its own route:
+    /// `claimed_sum_is_consistent` is false because the sum differs from
+    /// `recompute_claimed_sum`, and `assert_constraints` rejects the mismatched boundary and
+    /// reports a row index within the trace.
+    #[test]
+    fn test_check_component_reports_inconsistent_claimed_sum() {
+        use crate::components::memory::table::MemoryRow;
+
+        let mut table = MemoryTable::default();
+        for i in 0..(1u32 << stwo_prover::core::backend::simd::m31::LOG_N_LANES) {
+            table.add_row(MemoryRow {
+                clk: BaseField::from(i),
+                mp: BaseField::zero(),
+                value: BaseField::zero(),
+                multiplicity: BaseField::from(1u32),
+            });
+        }
+
+        let (main_trace, claim) = table.trace_evaluation().unwrap();
+        let lookup_elements = MemoryElements::draw(&mut Blake2sChannel::default());
+        let (interaction_trace, interaction_claim) =
+            memory_interaction_trace_evaluation(&main_trace, &lookup_elements);
+
+        let wrong_claimed_sum = interaction_claim.claimed_sum + SecureField::from(BaseField::from(1u32));
+        let claimed_sum_is_consistent =
+            wrong_claimed_sum == table.recompute_claimed_sum(&lookup_elements);
+        let eval = MemoryEval::new(&claim, lookup_elements, &interaction_claim);
+
+        let check = check_component(
+            "memory",
+            claim.log_size,
+            &main_trace,
+            &interaction_trace,
+            eval,
+            wrong_claimed_sum,
+            claimed_sum_is_consistent,
+        );
+
+        assert!(!check.claimed_sum_is_consistent);
+        let (row, _) = check.first_violation.expect("a mismatched claimed_sum should be rejected");
+        assert!(row < 1usize << claim.log_size, "row {row} out of range");
+    }
+}
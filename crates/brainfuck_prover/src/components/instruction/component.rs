@@ -0,0 +1,71 @@
+use stwo_prover::{
+    constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry},
+    core::{channel::Channel, fields::qm31::SecureField},
+};
+
+use super::table::InstructionElements;
+use crate::components::InstructionClaim;
+
+pub type InstructionComponent = FrameworkComponent<InstructionEval>;
+
+/// The claim from the interaction phase for the Instruction component: the total LogUp
+/// `claimed_sum` of the fractions it contributes to the Processor/Instruction cross-table
+/// check.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct InteractionClaim {
+    pub claimed_sum: SecureField,
+}
+
+impl InteractionClaim {
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        channel.mix_felts(&[self.claimed_sum]);
+    }
+}
+
+/// The AIR evaluator for the Instruction component: it contributes one LogUp fraction per row
+/// for the `(ip, instruction, argument)` tuple, weighted by `multiplicity`. The cross-table
+/// LogUp argument only makes these tuples match the Processor table's fractions for the same
+/// tuples; neither table is constrained against the program's bytecode, since both are built
+/// from the execution trace.
+pub struct InstructionEval {
+    log_size: u32,
+    lookup_elements: InstructionElements,
+    claimed_sum: SecureField,
+}
+
+impl InstructionEval {
+    pub fn new(
+        claim: &InstructionClaim,
+        lookup_elements: InstructionElements,
+        interaction_claim: &InteractionClaim,
+    ) -> Self {
+        Self { log_size: claim.log_size, lookup_elements, claimed_sum: interaction_claim.claimed_sum }
+    }
+}
+
+impl FrameworkEval for InstructionEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let ip = eval.next_trace_mask();
+        let instruction = eval.next_trace_mask();
+        let argument = eval.next_trace_mask();
+        let multiplicity = eval.next_trace_mask();
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.lookup_elements,
+            -E::EF::from(multiplicity),
+            &[ip, instruction, argument],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,211 @@
+use brainfuck_vm::registers::Registers;
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::logup::LogupTraceGenerator,
+    core::{
+        backend::simd::{column::BaseColumn, m31::LOG_N_LANES, qm31::PackedSecureField, SimdBackend},
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
+        poly::{circle::CanonicCoset, BitReversedOrder},
+    },
+    relation,
+};
+
+use super::component::InteractionClaim;
+use crate::components::{InstructionClaim, TraceColumn, TraceError, TraceEval};
+
+relation!(InstructionElements, 3);
+
+/// One row of the Instruction (bytecode) table: the instruction pointer, the instruction
+/// held there and its argument, plus how many times the Processor table executes it.
+#[derive(Debug, Default, Clone)]
+pub struct InstructionRow {
+    pub ip: BaseField,
+    pub instruction: BaseField,
+    pub argument: BaseField,
+    pub multiplicity: BaseField,
+}
+
+/// The Instruction component's table: one row per execution step, recording the
+/// instruction fetched and executed at that step's instruction pointer.
+#[derive(Debug, Default, Clone)]
+pub struct InstructionTable {
+    pub rows: Vec<InstructionRow>,
+}
+
+impl InstructionTable {
+    pub fn add_row(&mut self, row: InstructionRow) {
+        self.rows.push(row);
+    }
+
+    /// Generates the main trace evaluation for the Instruction component, and the
+    /// [`InstructionClaim`] carrying its `log_size`.
+    pub fn trace_evaluation(&self) -> Result<(TraceEval, InstructionClaim), TraceError> {
+        if self.rows.is_empty() {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let log_size = self.rows.len().next_power_of_two().ilog2().max(LOG_N_LANES);
+        let domain = CanonicCoset::new(log_size).circle_domain();
+
+        let mut ip_col = vec![BaseField::zero(); 1 << log_size];
+        let mut instruction_col = vec![BaseField::zero(); 1 << log_size];
+        let mut argument_col = vec![BaseField::zero(); 1 << log_size];
+        let mut multiplicity_col = vec![BaseField::zero(); 1 << log_size];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            ip_col[i] = row.ip;
+            instruction_col[i] = row.instruction;
+            argument_col[i] = row.argument;
+            multiplicity_col[i] = row.multiplicity;
+        }
+
+        let trace = [ip_col, instruction_col, argument_col, multiplicity_col]
+            .into_iter()
+            .map(|col| CircleEvaluationBitReversed::new(domain, BaseColumn::from_iter(col)))
+            .collect();
+
+        Ok((trace, InstructionClaim::new(log_size)))
+    }
+
+    /// Recomputes this table's LogUp total directly from its own rows, using plain scalar
+    /// field arithmetic rather than the SIMD-packed `LogupTraceGenerator` the real
+    /// interaction trace goes through. Used by the off-circuit debugger to cross-check an
+    /// [`InteractionClaim::claimed_sum`] via a genuinely independent code path.
+    pub fn recompute_claimed_sum(&self, lookup_elements: &InstructionElements) -> SecureField {
+        self.rows
+            .iter()
+            .map(|row| {
+                let denom = lookup_elements.combine(&[row.ip, row.instruction, row.argument]);
+                -SecureField::from(row.multiplicity) * denom.inverse()
+            })
+            .sum()
+    }
+}
+
+type CircleEvaluationBitReversed =
+    stwo_prover::core::poly::circle::CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+impl From<&[Registers]> for InstructionTable {
+    fn from(registers: &[Registers]) -> Self {
+        let mut table = Self::default();
+        for reg in registers {
+            table.add_row(InstructionRow {
+                ip: reg.ip,
+                instruction: reg.ci,
+                argument: reg.ni,
+                multiplicity: BaseField::one(),
+            });
+        }
+        table
+    }
+}
+
+/// Columns of the Instruction table's main trace.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum InstructionColumn {
+    Ip,
+    Instruction,
+    Argument,
+    Multiplicity,
+}
+
+impl InstructionColumn {
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Ip => 0,
+            Self::Instruction => 1,
+            Self::Argument => 2,
+            Self::Multiplicity => 3,
+        }
+    }
+}
+
+impl TraceColumn for InstructionColumn {
+    fn count() -> usize {
+        4
+    }
+
+    fn interaction_count() -> usize {
+        stwo_prover::core::fields::qm31::SECURE_EXTENSION_DEGREE
+    }
+}
+
+/// Generates the interaction trace for the Instruction component: one LogUp fraction per
+/// row, whose denominator is the row's `(ip, instruction, argument)` combined through
+/// `lookup_elements` and whose numerator is the negated `multiplicity`, so that it cancels
+/// against the matching `is_real`-weighted fractions the Processor table emits for the same
+/// tuple.
+pub fn interaction_trace_evaluation(
+    main_trace: &TraceEval,
+    lookup_elements: &InstructionElements,
+) -> (TraceEval, InteractionClaim) {
+    let log_size = main_trace[0].domain.log_size();
+    let mut logup_gen = LogupTraceGenerator::new(log_size);
+
+    let mut col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let ip = main_trace[InstructionColumn::Ip.index()].data[row];
+        let instruction = main_trace[InstructionColumn::Instruction.index()].data[row];
+        let argument = main_trace[InstructionColumn::Argument.index()].data[row];
+        let multiplicity = main_trace[InstructionColumn::Multiplicity.index()].data[row];
+
+        let denom = lookup_elements.combine(&[ip, instruction, argument]);
+        col_gen.write_frac(row, -PackedSecureField::from(multiplicity), denom);
+    }
+    col_gen.finalize_col();
+
+    let (trace, claimed_sum) = logup_gen.finalize_last();
+    (trace, InteractionClaim { claimed_sum })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_evaluation_empty_table_errors() {
+        let table = InstructionTable::default();
+        assert!(matches!(table.trace_evaluation(), Err(TraceError::EmptyTrace)));
+    }
+
+    #[test]
+    fn test_trace_evaluation_pads_to_next_power_of_two() {
+        let mut table = InstructionTable::default();
+        for i in 0..3 {
+            table.add_row(InstructionRow {
+                ip: BaseField::from(i),
+                instruction: BaseField::from(b'+' as u32),
+                argument: BaseField::zero(),
+                multiplicity: BaseField::one(),
+            });
+        }
+
+        let (trace, claim) = table.trace_evaluation().unwrap();
+        assert_eq!(claim.log_size, LOG_N_LANES);
+        assert_eq!(trace.len(), InstructionColumn::count());
+    }
+
+    /// The negated, multiplicity-weighted fractions `interaction_trace_evaluation` writes into
+    /// the SIMD-packed `LogupTraceGenerator` must sum to the same total as
+    /// `recompute_claimed_sum`, which takes the same rows through plain scalar field
+    /// arithmetic instead.
+    #[test]
+    fn test_recompute_claimed_sum_matches_interaction_trace() {
+        let mut table = InstructionTable::default();
+        for i in 0..(1u32 << LOG_N_LANES) {
+            table.add_row(InstructionRow {
+                ip: BaseField::from(i),
+                instruction: BaseField::from(b'+' as u32),
+                argument: BaseField::zero(),
+                multiplicity: BaseField::one(),
+            });
+        }
+
+        let (main_trace, _) = table.trace_evaluation().unwrap();
+        let lookup_elements =
+            InstructionElements::draw(&mut stwo_prover::core::channel::Blake2sChannel::default());
+        let (_, interaction_claim) = interaction_trace_evaluation(&main_trace, &lookup_elements);
+
+        assert_eq!(interaction_claim.claimed_sum, table.recompute_claimed_sum(&lookup_elements));
+    }
+}
@@ -0,0 +1,66 @@
+use stwo_prover::{
+    constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry},
+    core::{channel::Channel, fields::qm31::SecureField},
+};
+
+use super::table::IoElements;
+use crate::components::IoClaim;
+
+pub type IoComponent = FrameworkComponent<IoEval>;
+
+/// The claim from the interaction phase for the IO component: the total LogUp
+/// `claimed_sum` of the fractions it contributes to the Processor/IO cross-table check.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct InteractionClaim {
+    pub claimed_sum: SecureField,
+}
+
+impl InteractionClaim {
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        channel.mix_felts(&[self.claimed_sum]);
+    }
+}
+
+/// The AIR evaluator for the IO component: it contributes one LogUp fraction per row for the
+/// `(clk, is_io, value)` tuple, weighted by `is_io` (always `1` here, since every row of a
+/// table built by [`IoTable::from_public`](super::table::IoTable::from_public) is a real
+/// public I/O byte). Unlike Memory and Instruction, this table's rows come from the public
+/// statement rather than from a copy of the Processor's own trace, so the Processor can only
+/// match this fraction by actually agreeing with the public tape.
+pub struct IoEval {
+    log_size: u32,
+    lookup_elements: IoElements,
+    claimed_sum: SecureField,
+}
+
+impl IoEval {
+    pub fn new(claim: &IoClaim, lookup_elements: IoElements, interaction_claim: &InteractionClaim) -> Self {
+        Self { log_size: claim.log_size, lookup_elements, claimed_sum: interaction_claim.claimed_sum }
+    }
+}
+
+impl FrameworkEval for IoEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let clk = eval.next_trace_mask();
+        let is_io = eval.next_trace_mask();
+        let value = eval.next_trace_mask();
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.lookup_elements,
+            -E::EF::from(is_io.clone()),
+            &[clk, is_io, value],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,230 @@
+use brainfuck_vm::registers::Registers;
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::logup::LogupTraceGenerator,
+    core::{
+        backend::simd::{column::BaseColumn, m31::LOG_N_LANES, qm31::PackedSecureField, SimdBackend},
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
+        poly::{circle::CanonicCoset, BitReversedOrder},
+    },
+    relation,
+};
+
+use super::component::InteractionClaim;
+use crate::components::{IoClaim, TraceColumn, TraceError, TraceEval};
+
+relation!(IoElements, 3);
+
+/// One row of the I/O table: the clock of a step, whether that step is a `PutChar` or
+/// `ReadChar` (its `is_io` flag doubles as the row's LogUp multiplicity, so steps that never
+/// touch I/O contribute nothing to the cross-table check), and the byte that crossed the
+/// boundary on that step. Carrying `value` (not just `clk`/`is_io`) is what ties the
+/// cross-table argument to the actual bytes read and written, rather than merely to how many
+/// steps touched I/O.
+#[derive(Debug, Default, Clone)]
+pub struct IoRow {
+    pub clk: BaseField,
+    pub is_io: BaseField,
+    pub value: BaseField,
+}
+
+/// The IO component's table: one row per byte on the public I/O tape, built by
+/// [`IoTable::from_public`].
+#[derive(Debug, Default, Clone)]
+pub struct IoTable {
+    pub rows: Vec<IoRow>,
+}
+
+impl IoTable {
+    pub fn add_row(&mut self, row: IoRow) {
+        self.rows.push(row);
+    }
+
+    /// Generates the main trace evaluation for the IO component, and the [`IoClaim`]
+    /// carrying its `log_size`.
+    pub fn trace_evaluation(&self) -> Result<(TraceEval, IoClaim), TraceError> {
+        if self.rows.is_empty() {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let log_size = self.rows.len().next_power_of_two().ilog2().max(LOG_N_LANES);
+        let domain = CanonicCoset::new(log_size).circle_domain();
+
+        let mut clk_col = vec![BaseField::zero(); 1 << log_size];
+        let mut is_io_col = vec![BaseField::zero(); 1 << log_size];
+        let mut value_col = vec![BaseField::zero(); 1 << log_size];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            clk_col[i] = row.clk;
+            is_io_col[i] = row.is_io;
+            value_col[i] = row.value;
+        }
+
+        let trace = [clk_col, is_io_col, value_col]
+            .into_iter()
+            .map(|col| CircleEvaluationBitReversed::new(domain, BaseColumn::from_iter(col)))
+            .collect();
+
+        Ok((trace, IoClaim::new(log_size)))
+    }
+
+    /// Recomputes this table's LogUp total directly from its own rows, using plain scalar
+    /// field arithmetic rather than the SIMD-packed `LogupTraceGenerator` the real
+    /// interaction trace goes through. Used by the off-circuit debugger to cross-check an
+    /// [`InteractionClaim::claimed_sum`] via a genuinely independent code path.
+    pub fn recompute_claimed_sum(&self, lookup_elements: &IoElements) -> SecureField {
+        self.rows
+            .iter()
+            .map(|row| {
+                let denom = lookup_elements.combine(&[row.clk, row.is_io, row.value]);
+                -SecureField::from(row.is_io) * denom.inverse()
+            })
+            .sum()
+    }
+}
+
+type CircleEvaluationBitReversed =
+    stwo_prover::core::poly::circle::CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+impl IoTable {
+    /// Builds the IO table from the public I/O boundary rather than from the Processor's
+    /// own execution trace: every row is one byte the verifier already knows crossed the
+    /// boundary (via [`BrainfuckClaim::public_input`]/[`public_output`]), at the clock it
+    /// crossed it. Unlike a table built by copying the Processor's own `Registers`, this
+    /// means the Processor/IO cross-table LogUp argument actually forces the Processor's
+    /// `(clk, is_io, value)` entries for its real I/O steps to match this public tape,
+    /// instead of merely matching an unconstrained copy of itself.
+    ///
+    /// [`BrainfuckClaim::public_input`]: crate::brainfuck_air::BrainfuckClaim::public_input
+    /// [`public_output`]: crate::brainfuck_air::BrainfuckClaim::public_output
+    pub fn from_public(
+        public_input: &[(BaseField, BaseField)],
+        public_output: &[(BaseField, BaseField)],
+    ) -> Self {
+        let mut table = Self::default();
+        for &(clk, value) in public_input.iter().chain(public_output) {
+            table.add_row(IoRow { clk, is_io: BaseField::one(), value });
+        }
+        table
+    }
+}
+
+/// Splits a program's `,`/`.` steps into the `(clk, byte)` pairs crossing the public I/O
+/// boundary, in execution order: the bytes consumed by `ReadChar` and the bytes emitted by
+/// `PutChar` respectively. This is both what [`BrainfuckClaim::public_input`]/`public_output`
+/// carry and what [`IoTable::from_public`] builds its rows from, so the two always agree on
+/// what the public tape actually contains.
+///
+/// [`BrainfuckClaim::public_input`]: crate::brainfuck_air::BrainfuckClaim::public_input
+pub fn public_io_events(
+    registers: &[Registers],
+) -> (Vec<(BaseField, BaseField)>, Vec<(BaseField, BaseField)>) {
+    let mut public_input = Vec::new();
+    let mut public_output = Vec::new();
+    for reg in registers {
+        if reg.ci == BaseField::from(b',' as u32) {
+            public_input.push((reg.clk, reg.mv));
+        } else if reg.ci == BaseField::from(b'.' as u32) {
+            public_output.push((reg.clk, reg.mv));
+        }
+    }
+    (public_input, public_output)
+}
+
+/// Columns of the IO table's main trace.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum IoColumn {
+    Clk,
+    IsIo,
+    Value,
+}
+
+impl IoColumn {
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Clk => 0,
+            Self::IsIo => 1,
+            Self::Value => 2,
+        }
+    }
+}
+
+impl TraceColumn for IoColumn {
+    fn count() -> usize {
+        3
+    }
+
+    fn interaction_count() -> usize {
+        stwo_prover::core::fields::qm31::SECURE_EXTENSION_DEGREE
+    }
+}
+
+/// Generates the interaction trace for the IO component: one LogUp fraction per row (`is_io`
+/// is always `1` here, since every row is a public I/O byte), whose denominator is the row's
+/// `(clk, is_io, value)` combined through `lookup_elements` and whose numerator is `-1`. This
+/// cancels against the Processor's `+is_io` fraction for the same `(clk, is_io, value)` tuple
+/// only if the Processor recorded, at that exact clock, the same byte this public row does.
+pub fn interaction_trace_evaluation(
+    main_trace: &TraceEval,
+    lookup_elements: &IoElements,
+) -> (TraceEval, InteractionClaim) {
+    let log_size = main_trace[0].domain.log_size();
+    let mut logup_gen = LogupTraceGenerator::new(log_size);
+
+    let mut col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let clk = main_trace[IoColumn::Clk.index()].data[row];
+        let is_io = main_trace[IoColumn::IsIo.index()].data[row];
+        let value = main_trace[IoColumn::Value.index()].data[row];
+
+        let denom = lookup_elements.combine(&[clk, is_io, value]);
+        col_gen.write_frac(row, -PackedSecureField::from(is_io), denom);
+    }
+    col_gen.finalize_col();
+
+    let (trace, claimed_sum) = logup_gen.finalize_last();
+    (trace, InteractionClaim { claimed_sum })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_evaluation_empty_table_errors() {
+        let table = IoTable::default();
+        assert!(matches!(table.trace_evaluation(), Err(TraceError::EmptyTrace)));
+    }
+
+    #[test]
+    fn test_from_public_builds_one_row_per_byte() {
+        let public_input = vec![(BaseField::from(1u32), BaseField::from(b'a' as u32))];
+        let public_output = vec![
+            (BaseField::from(2u32), BaseField::from(b'b' as u32)),
+            (BaseField::from(3u32), BaseField::from(b'c' as u32)),
+        ];
+
+        let table = IoTable::from_public(&public_input, &public_output);
+
+        assert_eq!(table.rows.len(), public_input.len() + public_output.len());
+        assert!(table.rows.iter().all(|row| row.is_io == BaseField::one()));
+    }
+
+    /// The negated `is_io`-weighted fractions `interaction_trace_evaluation` writes into the
+    /// SIMD-packed `LogupTraceGenerator` must sum to the same total as
+    /// `recompute_claimed_sum`, which takes the same rows through plain scalar field
+    /// arithmetic instead.
+    #[test]
+    fn test_recompute_claimed_sum_matches_interaction_trace() {
+        let public_input: Vec<_> =
+            (0..(1u32 << LOG_N_LANES)).map(|i| (BaseField::from(i), BaseField::from(i))).collect();
+        let table = IoTable::from_public(&public_input, &[]);
+
+        let (main_trace, _) = table.trace_evaluation().unwrap();
+        let lookup_elements =
+            IoElements::draw(&mut stwo_prover::core::channel::Blake2sChannel::default());
+        let (_, interaction_claim) = interaction_trace_evaluation(&main_trace, &lookup_elements);
+
+        assert_eq!(interaction_claim.claimed_sum, table.recompute_claimed_sum(&lookup_elements));
+    }
+}
@@ -0,0 +1,70 @@
+use stwo_prover::{
+    constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry},
+    core::{channel::Channel, fields::qm31::SecureField},
+};
+
+use super::table::MemoryElements;
+use crate::components::MemoryClaim;
+
+pub type MemoryComponent = FrameworkComponent<MemoryEval>;
+
+/// The claim from the interaction phase for the Memory component: the total LogUp
+/// `claimed_sum` of the fractions it contributes to the Processor/Memory cross-table check.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct InteractionClaim {
+    pub claimed_sum: SecureField,
+}
+
+impl InteractionClaim {
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        channel.mix_felts(&[self.claimed_sum]);
+    }
+}
+
+/// The AIR evaluator for the Memory component: it contributes one LogUp fraction per row for
+/// the `(clk, mp, value)` tuple, weighted by `multiplicity`. The cross-table LogUp argument
+/// only makes these tuples match the Processor table's `is_real`-weighted fractions for the
+/// same tuples; neither this component nor the Processor constrains `value` to be what was
+/// last written at `mp`.
+pub struct MemoryEval {
+    log_size: u32,
+    lookup_elements: MemoryElements,
+    claimed_sum: SecureField,
+}
+
+impl MemoryEval {
+    pub fn new(
+        claim: &MemoryClaim,
+        lookup_elements: MemoryElements,
+        interaction_claim: &InteractionClaim,
+    ) -> Self {
+        Self { log_size: claim.log_size, lookup_elements, claimed_sum: interaction_claim.claimed_sum }
+    }
+}
+
+impl FrameworkEval for MemoryEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let clk = eval.next_trace_mask();
+        let mp = eval.next_trace_mask();
+        let value = eval.next_trace_mask();
+        let multiplicity = eval.next_trace_mask();
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.lookup_elements,
+            -E::EF::from(multiplicity),
+            &[clk, mp, value],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
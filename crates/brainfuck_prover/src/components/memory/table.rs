@@ -0,0 +1,214 @@
+use brainfuck_vm::registers::Registers;
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::logup::LogupTraceGenerator,
+    core::{
+        backend::simd::{column::BaseColumn, m31::LOG_N_LANES, qm31::PackedSecureField, SimdBackend},
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
+        poly::{circle::CanonicCoset, BitReversedOrder},
+    },
+    relation,
+};
+
+use super::component::InteractionClaim;
+use crate::components::{MemoryClaim, TraceColumn, TraceError, TraceEval};
+
+relation!(MemoryElements, 3);
+
+/// One row of the Memory table: the memory pointer and the value held there at a given
+/// clock, plus how many times the Processor table looks this exact tuple up.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryRow {
+    pub clk: BaseField,
+    pub mp: BaseField,
+    pub value: BaseField,
+    pub multiplicity: BaseField,
+}
+
+/// The Memory component's table: one row per execution step, recording the value held at
+/// the memory pointer at that clock.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryTable {
+    pub rows: Vec<MemoryRow>,
+}
+
+impl MemoryTable {
+    pub fn add_row(&mut self, row: MemoryRow) {
+        self.rows.push(row);
+    }
+
+    /// Generates the main trace evaluation for the Memory component, and the [`MemoryClaim`]
+    /// carrying its `log_size`.
+    pub fn trace_evaluation(&self) -> Result<(TraceEval, MemoryClaim), TraceError> {
+        if self.rows.is_empty() {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let log_size = self.rows.len().next_power_of_two().ilog2().max(LOG_N_LANES);
+        let domain = CanonicCoset::new(log_size).circle_domain();
+
+        let mut clk_col = vec![BaseField::zero(); 1 << log_size];
+        let mut mp_col = vec![BaseField::zero(); 1 << log_size];
+        let mut value_col = vec![BaseField::zero(); 1 << log_size];
+        let mut multiplicity_col = vec![BaseField::zero(); 1 << log_size];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            clk_col[i] = row.clk;
+            mp_col[i] = row.mp;
+            value_col[i] = row.value;
+            multiplicity_col[i] = row.multiplicity;
+        }
+
+        let trace = [clk_col, mp_col, value_col, multiplicity_col]
+            .into_iter()
+            .map(|col| CircleEvaluationBitReversed::new(domain, BaseColumn::from_iter(col)))
+            .collect();
+
+        Ok((trace, MemoryClaim::new(log_size)))
+    }
+
+    /// Recomputes this table's LogUp total directly from its own rows, using plain scalar
+    /// field arithmetic rather than the SIMD-packed [`LogupTraceGenerator`] the real
+    /// interaction trace goes through. Used by the off-circuit debugger to cross-check an
+    /// [`InteractionClaim::claimed_sum`] via a genuinely independent code path.
+    pub fn recompute_claimed_sum(&self, lookup_elements: &MemoryElements) -> SecureField {
+        self.rows
+            .iter()
+            .map(|row| {
+                let denom = lookup_elements.combine(&[row.clk, row.mp, row.value]);
+                -SecureField::from(row.multiplicity) * denom.inverse()
+            })
+            .sum()
+    }
+}
+
+/// Local alias so the `CircleEvaluation` construction above reads the same way across every
+/// component's `table.rs`.
+type CircleEvaluationBitReversed =
+    stwo_prover::core::poly::circle::CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+impl From<&[Registers]> for MemoryTable {
+    fn from(registers: &[Registers]) -> Self {
+        let mut table = Self::default();
+        for reg in registers {
+            table.add_row(MemoryRow {
+                clk: reg.clk,
+                mp: reg.mp,
+                value: reg.mv,
+                multiplicity: BaseField::one(),
+            });
+        }
+        table
+    }
+}
+
+/// Columns of the Memory table's main trace.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum MemoryColumn {
+    Clk,
+    Mp,
+    Value,
+    Multiplicity,
+}
+
+impl MemoryColumn {
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Clk => 0,
+            Self::Mp => 1,
+            Self::Value => 2,
+            Self::Multiplicity => 3,
+        }
+    }
+}
+
+impl TraceColumn for MemoryColumn {
+    fn count() -> usize {
+        4
+    }
+
+    fn interaction_count() -> usize {
+        // One LogUp fraction, stored as a `SecureField` (4 base field columns).
+        stwo_prover::core::fields::qm31::SECURE_EXTENSION_DEGREE
+    }
+}
+
+/// Generates the interaction trace for the Memory component: one LogUp fraction per row,
+/// whose denominator is the row's `(clk, mp, value)` combined through `lookup_elements` and
+/// whose numerator is the negated `multiplicity`, so that it cancels against the matching
+/// `is_real`-weighted fractions the Processor table emits for the same tuple.
+pub fn interaction_trace_evaluation(
+    main_trace: &TraceEval,
+    lookup_elements: &MemoryElements,
+) -> (TraceEval, InteractionClaim) {
+    let log_size = main_trace[0].domain.log_size();
+    let mut logup_gen = LogupTraceGenerator::new(log_size);
+
+    let mut col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let clk = main_trace[MemoryColumn::Clk.index()].data[row];
+        let mp = main_trace[MemoryColumn::Mp.index()].data[row];
+        let value = main_trace[MemoryColumn::Value.index()].data[row];
+        let multiplicity = main_trace[MemoryColumn::Multiplicity.index()].data[row];
+
+        let denom = lookup_elements.combine(&[clk, mp, value]);
+        col_gen.write_frac(row, -PackedSecureField::from(multiplicity), denom);
+    }
+    col_gen.finalize_col();
+
+    let (trace, claimed_sum) = logup_gen.finalize_last();
+    (trace, InteractionClaim { claimed_sum })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_evaluation_empty_table_errors() {
+        let table = MemoryTable::default();
+        assert!(matches!(table.trace_evaluation(), Err(TraceError::EmptyTrace)));
+    }
+
+    #[test]
+    fn test_trace_evaluation_pads_to_next_power_of_two() {
+        let mut table = MemoryTable::default();
+        for i in 0..3 {
+            table.add_row(MemoryRow {
+                clk: BaseField::from(i),
+                mp: BaseField::zero(),
+                value: BaseField::zero(),
+                multiplicity: BaseField::one(),
+            });
+        }
+
+        let (trace, claim) = table.trace_evaluation().unwrap();
+        assert_eq!(claim.log_size, LOG_N_LANES);
+        assert_eq!(trace.len(), MemoryColumn::count());
+    }
+
+    /// The negated, multiplicity-weighted fractions `interaction_trace_evaluation` writes into
+    /// the SIMD-packed `LogupTraceGenerator` must sum to the same total as
+    /// `recompute_claimed_sum`, which takes the same rows through plain scalar field
+    /// arithmetic instead - this is exactly what `debug_evaluate_constraints` relies on to
+    /// flag a component whose `claimed_sum` doesn't match its own rows.
+    #[test]
+    fn test_recompute_claimed_sum_matches_interaction_trace() {
+        let mut table = MemoryTable::default();
+        for i in 0..(1u32 << LOG_N_LANES) {
+            table.add_row(MemoryRow {
+                clk: BaseField::from(i),
+                mp: BaseField::zero(),
+                value: BaseField::from(i),
+                multiplicity: BaseField::one(),
+            });
+        }
+
+        let (main_trace, _) = table.trace_evaluation().unwrap();
+        let lookup_elements =
+            MemoryElements::draw(&mut stwo_prover::core::channel::Blake2sChannel::default());
+        let (_, interaction_claim) = interaction_trace_evaluation(&main_trace, &lookup_elements);
+
+        assert_eq!(interaction_claim.claimed_sum, table.recompute_claimed_sum(&lookup_elements));
+    }
+}
@@ -60,22 +60,23 @@ impl<T: TraceColumn> Claim<T> {
     /// - Main trace,
     /// - Interaction trace.
     ///
-    /// The number of columns of each trace is known before actually evaluating them.
-    /// The `log_size` is known once the main trace has been evaluated
-    /// (the log2 of the size of the [`super::table::MemoryTable`], to which we add
-    /// [`stwo_prover::core::backend::simd::m31::LOG_N_LANES`]
-    /// for the [`stwo_prover::core::backend::simd::SimdBackend`])
+    /// The number of columns of each trace is known before actually evaluating them, from
+    /// [`TraceColumn::preprocessed_count`], [`TraceColumn::count`] and
+    /// [`TraceColumn::interaction_count`] respectively. The `log_size` itself is known once
+    /// the main trace has been evaluated (the log2 of the size of the underlying table padded
+    /// to the next power of two, and at least
+    /// [`stwo_prover::core::backend::simd::m31::LOG_N_LANES`] for the
+    /// [`stwo_prover::core::backend::simd::SimdBackend`]), and is shared by all three trees:
+    /// the preprocessed and interaction columns are laid out over the same domain as the
+    /// main trace.
     ///
     /// Each element of the [`TreeVec`] is dedicated to the commitment of one type of trace.
     /// First element is for the preprocessed trace, second for the main trace and third for the
     /// interaction one.
-    ///
-    /// NOTE: Currently only the main trace is provided.
     pub fn log_sizes(&self) -> TreeVec<Vec<u32>> {
-        // TODO: Add the preprocessed and interaction trace correct sizes
-        let preprocessed_trace_log_sizes: Vec<u32> = vec![];
+        let preprocessed_trace_log_sizes = vec![self.log_size; T::preprocessed_count()];
         let trace_log_sizes = vec![self.log_size; T::count()];
-        let interaction_trace_log_sizes: Vec<u32> = vec![];
+        let interaction_trace_log_sizes = vec![self.log_size; T::interaction_count()];
         TreeVec::new(vec![
             preprocessed_trace_log_sizes,
             trace_log_sizes,
@@ -94,4 +95,45 @@ impl<T: TraceColumn> Claim<T> {
 pub trait TraceColumn {
     /// Returns the number of columns associated with the specific trace type.
     fn count() -> usize;
+
+    /// Returns the number of preprocessed columns (e.g. the `IsFirst` column) this trace
+    /// type contributes to the preprocessed trace tree.
+    fn preprocessed_count() -> usize {
+        1
+    }
+
+    /// Returns the number of interaction (LogUp) columns this trace type contributes to
+    /// the interaction trace tree.
+    fn interaction_count() -> usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use stwo_prover::constraint_framework::{
+        INTERACTION_TRACE_IDX, ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX,
+    };
+
+    use super::*;
+
+    struct DummyColumn;
+
+    impl TraceColumn for DummyColumn {
+        fn count() -> usize {
+            3
+        }
+
+        fn interaction_count() -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn test_claim_log_sizes() {
+        let claim = Claim::<DummyColumn>::new(5);
+        let log_sizes = claim.log_sizes();
+
+        assert_eq!(log_sizes[PREPROCESSED_TRACE_IDX], vec![5]);
+        assert_eq!(log_sizes[ORIGINAL_TRACE_IDX], vec![5; 3]);
+        assert_eq!(log_sizes[INTERACTION_TRACE_IDX], vec![5; 2]);
+    }
 }
@@ -0,0 +1,106 @@
+use stwo_prover::{
+    constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry},
+    core::{channel::Channel, fields::qm31::SecureField},
+};
+
+use crate::components::{
+    instruction::table::InstructionElements, io::table::IoElements, memory::table::MemoryElements,
+    ProcessorClaim,
+};
+
+pub type ProcessorComponent = FrameworkComponent<ProcessorEval>;
+
+/// The claim from the interaction phase for the Processor component: the total LogUp
+/// `claimed_sum` of the fractions it contributes to its three cross-table checks (Memory,
+/// Instruction, IO).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct InteractionClaim {
+    pub claimed_sum: SecureField,
+}
+
+impl InteractionClaim {
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        channel.mix_felts(&[self.claimed_sum]);
+    }
+}
+
+/// The AIR evaluator for the Processor component. It has no internal transition
+/// constraints of its own yet; its role is to assert, through LogUp, that every row's
+/// `(clk, mp, value)`, `(ip, instruction, argument)` and `(clk, is_io, value)` tuples are
+/// each matched by an entry in the Memory, Instruction and IO tables respectively.
+pub struct ProcessorEval {
+    log_size: u32,
+    memory_lookup_elements: MemoryElements,
+    instruction_lookup_elements: InstructionElements,
+    io_lookup_elements: IoElements,
+    claimed_sum: SecureField,
+}
+
+impl ProcessorEval {
+    pub fn new(
+        claim: &ProcessorClaim,
+        memory_lookup_elements: MemoryElements,
+        instruction_lookup_elements: InstructionElements,
+        io_lookup_elements: IoElements,
+        interaction_claim: &InteractionClaim,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            memory_lookup_elements,
+            instruction_lookup_elements,
+            io_lookup_elements,
+            claimed_sum: interaction_claim.claimed_sum,
+        }
+    }
+}
+
+impl FrameworkEval for ProcessorEval {
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let clk = eval.next_trace_mask();
+        let ip = eval.next_trace_mask();
+        let mp = eval.next_trace_mask();
+        let value = eval.next_trace_mask();
+        let instruction = eval.next_trace_mask();
+        let argument = eval.next_trace_mask();
+        let is_io = eval.next_trace_mask();
+        let is_real = eval.next_trace_mask();
+
+        // Every step's `(clk, mp, value)` must be present in the Memory table. Weighted by
+        // `is_real`, not a constant `1`, so padding rows - which the Memory table pads with
+        // `multiplicity = 0` - contribute a matching `0` fraction here too.
+        eval.add_to_relation(RelationEntry::new(
+            &self.memory_lookup_elements,
+            E::EF::from(is_real.clone()),
+            &[clk.clone(), mp, value.clone()],
+        ));
+
+        // Every step's `(ip, instruction, argument)` must be present in the Instruction
+        // table, likewise weighted by `is_real`.
+        eval.add_to_relation(RelationEntry::new(
+            &self.instruction_lookup_elements,
+            E::EF::from(is_real),
+            &[ip, instruction, argument],
+        ));
+
+        // Only `PutChar`/`ReadChar` steps must be present in the IO table; `is_io` zeroes
+        // the fraction's numerator out for every other step. Including `value` ties this to
+        // the actual byte transferred, not just to the fact that some byte was.
+        eval.add_to_relation(RelationEntry::new(
+            &self.io_lookup_elements,
+            E::EF::from(is_io.clone()),
+            &[clk, is_io, value],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,338 @@
+use brainfuck_vm::registers::Registers;
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::logup::LogupTraceGenerator,
+    core::{
+        backend::simd::{column::BaseColumn, m31::LOG_N_LANES, qm31::PackedSecureField, SimdBackend},
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
+        poly::{circle::CanonicCoset, BitReversedOrder},
+    },
+};
+
+use super::component::InteractionClaim;
+use crate::components::{
+    instruction::table::InstructionElements, io::table::IoElements, memory::table::MemoryElements,
+    ProcessorClaim, TraceColumn, TraceError, TraceEval,
+};
+
+/// One row of the Processor table: the full state of one execution step. This is the
+/// single source of truth the Memory, Instruction and IO tables are each checked against
+/// through a LogUp cross-table argument.
+#[derive(Debug, Default, Clone)]
+pub struct ProcessorRow {
+    pub clk: BaseField,
+    pub ip: BaseField,
+    pub mp: BaseField,
+    pub value: BaseField,
+    pub instruction: BaseField,
+    pub argument: BaseField,
+    pub is_io: BaseField,
+    /// `1` for every row actually pushed via [`ProcessorTable::add_row`], `0` on the padding
+    /// rows `trace_evaluation` zero-fills up to the next power of two. The Memory and
+    /// Instruction tables are padded the same way, with `multiplicity = 0` on those same
+    /// rows, so this flag - not a hardcoded constant - must weight the Processor's memory
+    /// and instruction LogUp fractions for the two sides to cancel on padding rows too.
+    pub is_real: BaseField,
+}
+
+/// The Processor component's table: one row per execution step (fetch-decode-execute).
+#[derive(Debug, Default, Clone)]
+pub struct ProcessorTable {
+    pub rows: Vec<ProcessorRow>,
+}
+
+impl ProcessorTable {
+    pub fn add_row(&mut self, row: ProcessorRow) {
+        self.rows.push(row);
+    }
+
+    /// Generates the main trace evaluation for the Processor component, and the
+    /// [`ProcessorClaim`] carrying its `log_size`.
+    pub fn trace_evaluation(&self) -> Result<(TraceEval, ProcessorClaim), TraceError> {
+        if self.rows.is_empty() {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let log_size = self.rows.len().next_power_of_two().ilog2().max(LOG_N_LANES);
+        let domain = CanonicCoset::new(log_size).circle_domain();
+
+        let mut clk_col = vec![BaseField::zero(); 1 << log_size];
+        let mut ip_col = vec![BaseField::zero(); 1 << log_size];
+        let mut mp_col = vec![BaseField::zero(); 1 << log_size];
+        let mut value_col = vec![BaseField::zero(); 1 << log_size];
+        let mut instruction_col = vec![BaseField::zero(); 1 << log_size];
+        let mut argument_col = vec![BaseField::zero(); 1 << log_size];
+        let mut is_io_col = vec![BaseField::zero(); 1 << log_size];
+        let mut is_real_col = vec![BaseField::zero(); 1 << log_size];
+
+        for (i, row) in self.rows.iter().enumerate() {
+            clk_col[i] = row.clk;
+            ip_col[i] = row.ip;
+            mp_col[i] = row.mp;
+            value_col[i] = row.value;
+            instruction_col[i] = row.instruction;
+            argument_col[i] = row.argument;
+            is_io_col[i] = row.is_io;
+            is_real_col[i] = row.is_real;
+        }
+
+        let trace =
+            [clk_col, ip_col, mp_col, value_col, instruction_col, argument_col, is_io_col, is_real_col]
+                .into_iter()
+                .map(|col| CircleEvaluationBitReversed::new(domain, BaseColumn::from_iter(col)))
+                .collect();
+
+        Ok((trace, ProcessorClaim::new(log_size)))
+    }
+
+    /// Recomputes this table's LogUp total directly from its own rows, using plain scalar
+    /// field arithmetic rather than the SIMD-packed `LogupTraceGenerator` the real
+    /// interaction trace goes through. Used by the off-circuit debugger to cross-check an
+    /// [`InteractionClaim::claimed_sum`] via a genuinely independent code path.
+    pub fn recompute_claimed_sum(
+        &self,
+        memory_lookup_elements: &MemoryElements,
+        instruction_lookup_elements: &InstructionElements,
+        io_lookup_elements: &IoElements,
+    ) -> SecureField {
+        self.rows
+            .iter()
+            .map(|row| {
+                let memory_denom =
+                    memory_lookup_elements.combine(&[row.clk, row.mp, row.value]);
+                let instruction_denom = instruction_lookup_elements.combine(&[
+                    row.ip,
+                    row.instruction,
+                    row.argument,
+                ]);
+                let io_denom = io_lookup_elements.combine(&[row.clk, row.is_io, row.value]);
+
+                SecureField::from(row.is_real) * memory_denom.inverse()
+                    + SecureField::from(row.is_real) * instruction_denom.inverse()
+                    + SecureField::from(row.is_io) * io_denom.inverse()
+            })
+            .sum()
+    }
+}
+
+type CircleEvaluationBitReversed =
+    stwo_prover::core::poly::circle::CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+impl From<&[Registers]> for ProcessorTable {
+    fn from(registers: &[Registers]) -> Self {
+        let mut table = Self::default();
+        for reg in registers {
+            let is_put_or_read =
+                reg.ci == BaseField::from(b'.' as u32) || reg.ci == BaseField::from(b',' as u32);
+            table.add_row(ProcessorRow {
+                clk: reg.clk,
+                ip: reg.ip,
+                mp: reg.mp,
+                value: reg.mv,
+                instruction: reg.ci,
+                argument: reg.ni,
+                is_io: if is_put_or_read { BaseField::one() } else { BaseField::zero() },
+                is_real: BaseField::one(),
+            });
+        }
+        table
+    }
+}
+
+/// Columns of the Processor table's main trace.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ProcessorColumn {
+    Clk,
+    Ip,
+    Mp,
+    Value,
+    Instruction,
+    Argument,
+    IsIo,
+    IsReal,
+}
+
+impl ProcessorColumn {
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Clk => 0,
+            Self::Ip => 1,
+            Self::Mp => 2,
+            Self::Value => 3,
+            Self::Instruction => 4,
+            Self::Argument => 5,
+            Self::IsIo => 6,
+            Self::IsReal => 7,
+        }
+    }
+}
+
+impl TraceColumn for ProcessorColumn {
+    fn count() -> usize {
+        8
+    }
+
+    fn interaction_count() -> usize {
+        // One LogUp fraction per cross-table link (Memory, Instruction, IO), each a
+        // `SecureField` (4 base field columns).
+        3 * stwo_prover::core::fields::qm31::SECURE_EXTENSION_DEGREE
+    }
+}
+
+/// Generates the interaction trace for the Processor component: for every row, it emits
+/// three LogUp fractions combining the row's `(clk, mp, value)`, `(ip, instruction,
+/// argument)` and `(clk, is_io, value)` through `memory_lookup_elements`,
+/// `instruction_lookup_elements` and `io_lookup_elements` respectively. The memory and
+/// instruction fractions are weighted
+/// by `is_real` rather than a constant `1`: padding rows (zero-filled past `rows.len()`) must
+/// contribute a `0` numerator here too, since the Memory and Instruction tables pad with
+/// `multiplicity = 0` on their own side, and an execution whose row count isn't a power of
+/// two would otherwise be padded with mismatched, non-cancelling fractions. These are the
+/// exact counterparts of the negated, multiplicity-weighted fractions the Memory,
+/// Instruction and IO tables emit for the same tuples, so the combined sum cancels out iff
+/// the processor trace is consistent with the other three tables.
+pub fn interaction_trace_evaluation(
+    main_trace: &TraceEval,
+    memory_lookup_elements: &MemoryElements,
+    instruction_lookup_elements: &InstructionElements,
+    io_lookup_elements: &IoElements,
+) -> (TraceEval, InteractionClaim) {
+    let log_size = main_trace[0].domain.log_size();
+    let mut logup_gen = LogupTraceGenerator::new(log_size);
+
+    let mut memory_col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let clk = main_trace[ProcessorColumn::Clk.index()].data[row];
+        let mp = main_trace[ProcessorColumn::Mp.index()].data[row];
+        let value = main_trace[ProcessorColumn::Value.index()].data[row];
+        let is_real = main_trace[ProcessorColumn::IsReal.index()].data[row];
+
+        let denom = memory_lookup_elements.combine(&[clk, mp, value]);
+        memory_col_gen.write_frac(row, PackedSecureField::from(is_real), denom);
+    }
+    memory_col_gen.finalize_col();
+
+    let mut instruction_col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let ip = main_trace[ProcessorColumn::Ip.index()].data[row];
+        let instruction = main_trace[ProcessorColumn::Instruction.index()].data[row];
+        let argument = main_trace[ProcessorColumn::Argument.index()].data[row];
+        let is_real = main_trace[ProcessorColumn::IsReal.index()].data[row];
+
+        let denom = instruction_lookup_elements.combine(&[ip, instruction, argument]);
+        instruction_col_gen.write_frac(row, PackedSecureField::from(is_real), denom);
+    }
+    instruction_col_gen.finalize_col();
+
+    let mut io_col_gen = logup_gen.new_col();
+    for row in 0..1 << (log_size - LOG_N_LANES) {
+        let clk = main_trace[ProcessorColumn::Clk.index()].data[row];
+        let is_io = main_trace[ProcessorColumn::IsIo.index()].data[row];
+        let value = main_trace[ProcessorColumn::Value.index()].data[row];
+
+        let denom = io_lookup_elements.combine(&[clk, is_io, value]);
+        io_col_gen.write_frac(row, PackedSecureField::from(is_io), denom);
+    }
+    io_col_gen.finalize_col();
+
+    let (trace, claimed_sum) = logup_gen.finalize_last();
+    (trace, InteractionClaim { claimed_sum })
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Zero;
+    use stwo_prover::core::{channel::Blake2sChannel, fields::qm31::SecureField};
+
+    use super::*;
+    use crate::components::{
+        instruction::table::{InstructionRow, InstructionTable},
+        io::table::IoRow,
+        memory::table::{MemoryRow, MemoryTable},
+    };
+
+    /// Builds a consistent synthetic execution (one `+` step per row, touching no I/O) and
+    /// checks that the Processor's `(clk, mp, value)`, `(ip, instruction, argument)` and
+    /// `(clk, is_io, value)` fractions exactly cancel the negated, multiplicity-weighted fractions
+    /// the Memory, Instruction and IO tables emit for the same rows - i.e. that the
+    /// cross-table LogUp permutation argument actually holds for a consistent trace.
+    ///
+    /// `n_rows` is deliberately *not* a power of two: `trace_evaluation` pads every table up
+    /// to the next one with zero-filled rows, and the Processor's memory/instruction
+    /// fractions must weight those padding rows by `0` (via `is_real`), exactly like the
+    /// padding `multiplicity = 0` on the Memory/Instruction side, or the two sides would
+    /// stop cancelling for any execution whose step count isn't already a power of two.
+    #[test]
+    fn test_cross_table_logup_cancels_for_consistent_trace() {
+        let n_rows = (1usize << LOG_N_LANES) + 3;
+
+        let mut processor_table = ProcessorTable::default();
+        let mut memory_table = MemoryTable::default();
+        let mut instruction_table = InstructionTable::default();
+        let mut io_table = crate::components::io::table::IoTable::default();
+
+        for i in 0..n_rows {
+            let clk = BaseField::from(i as u32);
+            let ip = BaseField::from(i as u32);
+            let mp = BaseField::zero();
+            let value = BaseField::from(1u32);
+            let instruction = BaseField::from(b'+' as u32);
+            let argument = BaseField::zero();
+            let is_io = BaseField::zero();
+
+            processor_table.add_row(ProcessorRow {
+                clk,
+                ip,
+                mp,
+                value,
+                instruction,
+                argument,
+                is_io,
+                is_real: BaseField::one(),
+            });
+            memory_table.add_row(MemoryRow { clk, mp, value, multiplicity: BaseField::one() });
+            instruction_table.add_row(InstructionRow {
+                ip,
+                instruction,
+                argument,
+                multiplicity: BaseField::one(),
+            });
+            io_table.add_row(IoRow { clk, is_io, value });
+        }
+
+        let (processor_trace, _) = processor_table.trace_evaluation().unwrap();
+        let (memory_trace, _) = memory_table.trace_evaluation().unwrap();
+        let (instruction_trace, _) = instruction_table.trace_evaluation().unwrap();
+        let (io_trace, _) = io_table.trace_evaluation().unwrap();
+
+        let channel = &mut Blake2sChannel::default();
+        let memory_lookup_elements = MemoryElements::draw(channel);
+        let instruction_lookup_elements = InstructionElements::draw(channel);
+        let io_lookup_elements = IoElements::draw(channel);
+
+        let (_, memory_interaction_claim) = crate::components::memory::table::interaction_trace_evaluation(
+            &memory_trace,
+            &memory_lookup_elements,
+        );
+        let (_, instruction_interaction_claim) =
+            crate::components::instruction::table::interaction_trace_evaluation(
+                &instruction_trace,
+                &instruction_lookup_elements,
+            );
+        let (_, io_interaction_claim) =
+            crate::components::io::table::interaction_trace_evaluation(&io_trace, &io_lookup_elements);
+        let (_, processor_interaction_claim) = interaction_trace_evaluation(
+            &processor_trace,
+            &memory_lookup_elements,
+            &instruction_lookup_elements,
+            &io_lookup_elements,
+        );
+
+        let total_sum = memory_interaction_claim.claimed_sum
+            + instruction_interaction_claim.claimed_sum
+            + io_interaction_claim.claimed_sum
+            + processor_interaction_claim.claimed_sum;
+
+        assert_eq!(total_sum, SecureField::zero());
+    }
+}